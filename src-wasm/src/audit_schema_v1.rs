@@ -8,6 +8,11 @@
 //! - Breaking changes require major version bump
 
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Current schema version (stamped on every AuditResultV1 / AuditDiffV1)
+pub const SCHEMA_VERSION: &str = "1.0.0";
 
 // ============================================================================
 // ROOT RESULT
@@ -33,6 +38,26 @@ pub struct AuditResultV1 {
     
     /// Zapier plan utilization analysis
     pub plan_analysis: PlanAnalysis,
+    
+    /// Account-level patterns spanning several Zaps
+    #[serde(default)]
+    pub pattern_findings: Vec<PatternFinding>,
+    
+    /// Zaps skipped because they failed to deserialize (tolerant parsing only)
+    #[serde(default)]
+    pub parse_warnings: Vec<String>,
+    
+    /// Error messages tallied across all analyzed Zaps, most frequent first
+    #[serde(default)]
+    pub error_overview: Vec<ErrorMessageSummary>,
+    
+    /// Waste and flag totals per Zapier folder, highest waste first
+    #[serde(default)]
+    pub by_folder: Vec<FolderSummary>,
+    
+    /// Export-level warnings (e.g. no Zaps in the export)
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
 }
 
 impl AuditResultV1 {
@@ -43,14 +68,20 @@ impl AuditResultV1 {
         per_zap_findings: Vec<ZapFinding>,
         opportunities_ranked: Vec<RankedOpportunity>,
         plan_analysis: PlanAnalysis,
+        pattern_findings: Vec<PatternFinding>,
     ) -> Self {
         Self {
-            schema_version: "1.0.0".to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
             audit_metadata,
             global_metrics,
             per_zap_findings,
             opportunities_ranked,
             plan_analysis,
+            pattern_findings,
+            parse_warnings: Vec::new(),
+            error_overview: Vec::new(),
+            by_folder: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -73,6 +104,22 @@ pub struct AuditMetadata {
     
     /// Distribution of confidence levels
     pub confidence_overview: ConfidenceOverview,
+    
+    /// Zaps with matching task history rows (out of `total_zaps`)
+    #[serde(default)]
+    pub zaps_with_history: u32,
+    
+    /// Zaps analyzed
+    #[serde(default)]
+    pub total_zaps: u32,
+    
+    /// Task history rows ignored (empty or invalid zap_id, repeated header rows, missing status, unreadable rows)
+    #[serde(default)]
+    pub csv_rows_skipped: u32,
+    
+    /// Confidence weights used for confidence-weighted waste and priority scores
+    #[serde(default)]
+    pub confidence_weights: ConfidenceWeights,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,8 +136,40 @@ pub struct PricingAssumptions {
     /// Zapier plan tier (e.g., "Professional", "Team")
     pub plan_tier: String,
     
-    /// Cost per task in USD
+    /// Cost per task (in `currency_code`, despite the field name)
     pub task_price_usd: f32,
+    
+    /// ISO 4217 code for every `*_usd` figure in this result; values are
+    /// converted, field names are kept for schema stability
+    #[serde(default = "default_currency_code")]
+    pub currency_code: String,
+    
+    /// Rank of the tier's per-task price within its plan (0 = cheapest, 1 = most
+    /// expensive); near 1 means per-task savings shrink on larger tiers. None for custom rates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_task_percentile: Option<f32>,
+    
+    /// How saved tasks are valued: "average" (tasks × task_price_usd) or
+    /// "marginal" (only the tier-price drop the reduced usage achieves)
+    #[serde(default = "default_pricing_mode")]
+    pub pricing_mode: String,
+    
+    /// Caveat about how task_price_usd was derived (e.g. Enterprise priced from
+    /// public Team tiers because no contracted rate was given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_note: Option<String>,
+    
+    /// Units of `currency_code` per 1 USD used to convert prices (None for USD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fx_rate: Option<f32>,
+}
+
+fn default_currency_code() -> String {
+    "USD".to_string()
+}
+
+fn default_pricing_mode() -> String {
+    "average".to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +184,33 @@ pub struct ConfidenceOverview {
     pub low: u32,
 }
 
+/// Share of estimated savings counted per confidence level (0-1); drives
+/// confidence-weighted waste and priority scores
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfidenceWeights {
+    pub high: f32,
+    pub medium: f32,
+    pub low: f32,
+}
+
+impl Default for ConfidenceWeights {
+    fn default() -> Self {
+        Self { high: 1.0, medium: 0.6, low: 0.3 }
+    }
+}
+
+impl ConfidenceWeights {
+    /// Weight for one confidence level
+    pub fn weight(&self, confidence: ConfidenceLevel) -> f32 {
+        match confidence {
+            ConfidenceLevel::High => self.high,
+            ConfidenceLevel::Medium => self.medium,
+            ConfidenceLevel::Low => self.low,
+        }
+    }
+}
+
 // ============================================================================
 // GLOBAL METRICS
 // ============================================================================
@@ -118,6 +224,10 @@ pub struct GlobalMetrics {
     /// Number of Zaps with status "on"
     pub active_zaps: u32,
     
+    /// Number of Zaps with status "paused" (not active, never zombies)
+    #[serde(default)]
+    pub paused_zap_count: u32,
+    
     /// Total monthly task consumption
     pub total_monthly_tasks: u32,
     
@@ -130,11 +240,28 @@ pub struct GlobalMetrics {
     /// Estimated wasted cost per year in USD
     pub estimated_annual_waste_usd: f32,
     
+    /// Monthly waste weighted by flag confidence (conservative "likely savings")
+    #[serde(default)]
+    pub confidence_weighted_monthly_waste_usd: f32,
+    
     /// Number of zombie Zaps (on but not running)
     pub zombie_zap_count: u32,
     
     /// Number of high-severity flags
     pub high_severity_flag_count: u32,
+    
+    /// Number of branching points (steps with more than one child, e.g. Paths)
+    #[serde(default)]
+    pub fan_out_flows: u32,
+    
+    /// Share of Formatter/Code steps: "low" (<10%), "medium" (10–30%), "high" (>30%)
+    #[serde(default)]
+    pub formatter_usage_density: String,
+    
+    /// Monthly waste in USD per flag code (keys are SCREAMING_SNAKE flag codes)
+    /// BTreeMap so keys serialize in a stable order
+    #[serde(default)]
+    pub waste_by_flag_code: BTreeMap<FlagCode, f32>,
 }
 
 // ============================================================================
@@ -167,6 +294,129 @@ pub struct ZapFinding {
     
     /// Non-critical warnings
     pub warnings: Vec<Warning>,
+    
+    /// Sum of this Zap's flag monthly savings in USD
+    #[serde(default)]
+    pub total_monthly_savings_usd: f32,
+    
+    /// Sum of this Zap's flag annual savings in USD
+    #[serde(default)]
+    pub total_annual_savings_usd: f32,
+    
+    /// Ordered step list (only when requested via include_step_graph)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<StepInfo>,
+    
+    /// Per-step task and cost attribution (only when requested via include_step_costs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub step_costs: Vec<StepCost>,
+    
+    /// Raw task history stats (None in Partial mode or without rows for this Zap)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ZapUsage>,
+    
+    /// Zapier folder the Zap is filed under (None when the export has no folder data)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<ZapFolder>,
+}
+
+/// Zapier folder reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZapFolder {
+    /// Folder id as exported (numeric ids are stringified)
+    pub id: String,
+    
+    /// Folder name (falls back to the id when the export has no name)
+    pub name: String,
+}
+
+/// Estimated monthly cost of one step (trigger excluded)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepCost {
+    /// Step position, matching StepInfo.index
+    pub index: u32,
+    
+    /// Human-readable app name
+    pub app_name: String,
+    
+    /// Runs expected to reach this step per month (1 task each)
+    pub estimated_monthly_tasks: u32,
+    
+    /// estimated_monthly_tasks at the per-task price
+    pub estimated_monthly_usd: f32,
+}
+
+/// Execution statistics for a Zap, from task history CSVs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapUsage {
+    /// Runs found in task history
+    pub total_runs: u32,
+    
+    /// Successful runs
+    pub success_count: u32,
+    
+    /// Failed runs
+    pub error_count: u32,
+    
+    /// Error rate percentage (0-100)
+    pub error_rate: f32,
+    
+    /// Most frequent error message
+    pub most_common_error: Option<String>,
+    
+    /// Error trend ("increasing" | "stable" | "decreasing")
+    pub error_trend: Option<String>,
+    
+    /// Longest consecutive failure streak
+    pub max_streak: u32,
+    
+    /// Timestamp of the most recent run
+    pub last_run: Option<String>,
+    
+    /// Tasks consumed, when the export has a task count column
+    pub tasks_used: Option<u32>,
+    
+    /// When the Zap runs, present when task history has parsable timestamps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_distribution: Option<RunDistribution>,
+}
+
+/// Hour-of-day and day-of-week run counts for a Zap (UTC)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunDistribution {
+    /// Runs per hour of day, 24 entries (index 0 = 00:00-00:59)
+    pub hourly_runs: Vec<u32>,
+    
+    /// Runs per weekday, 7 entries (index 0 = Monday)
+    pub weekday_runs: Vec<u32>,
+    
+    /// Hours of the day spanned by runs (24 minus the longest run-free stretch)
+    pub active_hours: u32,
+}
+
+/// One step of a Zap, in branch-aware traversal order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepInfo {
+    /// Position in the traversal (0 = trigger)
+    pub index: u32,
+    
+    /// Index of the parent step, None for the trigger
+    pub parent_index: Option<u32>,
+    
+    /// Human-readable app name
+    pub app_name: String,
+    
+    /// Node type ("read" | "write" | ...)
+    pub type_of: String,
+    
+    /// Whether this is a Filter step
+    pub is_filter: bool,
+    
+    /// Whether this is a Formatter/Code step
+    pub is_formatter: bool,
+    
+    /// Whether this step is paused
+    pub paused: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -179,6 +429,14 @@ pub struct ZapMetrics {
     
     /// Task/step ratio
     pub task_step_ratio: f32,
+    
+    /// Error rate in percent (0-100), None without task history
+    #[serde(default)]
+    pub error_rate: Option<f32>,
+    
+    /// Total monthly spend on this Zap: monthly_tasks × task price (flags or not)
+    #[serde(default)]
+    pub estimated_monthly_cost_usd: f32,
 }
 
 // ============================================================================
@@ -191,6 +449,11 @@ pub struct EfficiencyFlag {
     /// Unique identifier for this issue type
     pub code: FlagCode,
     
+    /// Stable id for this specific flag, same across re-runs of the same input
+    /// (empty in audits produced before flag ids existed)
+    #[serde(default)]
+    pub flag_id: String,
+    
     /// Technical severity
     pub severity: Severity,
     
@@ -214,6 +477,14 @@ pub struct FlagImpact {
     
     /// Estimated annual savings in USD
     pub estimated_annual_savings_usd: f32,
+    
+    /// Conservative end of the monthly savings range (equals the estimate when data-backed)
+    #[serde(default)]
+    pub estimated_monthly_savings_low_usd: f32,
+    
+    /// Optimistic end of the monthly savings range (equals the estimate when data-backed)
+    #[serde(default)]
+    pub estimated_monthly_savings_high_usd: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -234,6 +505,10 @@ pub struct Warning {
     
     /// Human-readable explanation
     pub message: String,
+    
+    /// Warning-specific metadata (omitted when there is none)
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub meta: serde_json::Value,
 }
 
 // ============================================================================
@@ -246,6 +521,10 @@ pub struct RankedOpportunity {
     /// Which Zap this belongs to
     pub zap_id: String,
     
+    /// Human-readable Zap name (same as the matching ZapFinding)
+    #[serde(default)]
+    pub zap_name: String,
+    
     /// Which flag detected this
     pub flag_code: FlagCode,
     
@@ -255,7 +534,11 @@ pub struct RankedOpportunity {
     /// Confidence in estimate
     pub confidence: ConfidenceLevel,
     
-    /// Explicit ranking (1 = highest impact)
+    /// Fix priority: savings × confidence weight / effort hours (quick, sure wins score high)
+    #[serde(default)]
+    pub priority_score: f32,
+    
+    /// Explicit ranking (1 = first to act on, by savings or priority_score)
     pub rank: u32,
 }
 
@@ -283,6 +566,10 @@ pub struct PlanAnalysis {
     
     /// Whether downgrade is technically possible
     pub downgrade_safe: bool,
+    
+    /// Usage is above the plan's highest tier (Free: Zaps stop at the cap)
+    #[serde(default)]
+    pub exceeds_plan_limit: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -309,6 +596,142 @@ pub struct PremiumFeatures {
     pub custom_logic: bool,
 }
 
+// ============================================================================
+// CROSS-ZAP PATTERNS
+// ============================================================================
+
+/// Account-level finding spanning several Zaps (batch mode only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternFinding {
+    /// Pattern identifier (e.g. "duplicate_zaps")
+    pub pattern_type: String,
+    
+    /// Zaps involved in this pattern
+    pub zap_ids: Vec<String>,
+    
+    /// Human-readable explanation
+    pub message: String,
+    
+    /// Recommended action
+    pub guidance: String,
+    
+    /// Pattern-specific metadata
+    pub meta: serde_json::Value,
+}
+
+/// One error message aggregated across the account's task history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorMessageSummary {
+    /// Error message as it appears in task history
+    pub message: String,
+    
+    /// Number of Zaps that hit this error
+    pub affected_zap_count: u32,
+    
+    /// Total failed runs with this error across those Zaps
+    pub total_occurrences: u32,
+}
+
+/// Waste and flag totals for the Zaps in one folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderSummary {
+    /// Folder the totals belong to
+    pub folder: ZapFolder,
+    
+    /// Zaps filed in this folder
+    pub zap_count: u32,
+    
+    /// Flags across those Zaps
+    pub flag_count: u32,
+    
+    /// Sum of those Zaps' monthly savings in USD
+    pub estimated_monthly_waste_usd: f32,
+}
+
+// ============================================================================
+// AUDIT DIFF
+// ============================================================================
+
+/// Changes between two audits of the same account (month-over-month view)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditDiffV1 {
+    /// Schema version for backward compatibility
+    pub schema_version: String,
+    
+    /// Zap IDs present only in the current audit
+    pub zaps_added: Vec<String>,
+    
+    /// Zap IDs present only in the previous audit
+    pub zaps_removed: Vec<String>,
+    
+    /// Flags on Zaps in both audits that no longer appear
+    pub flags_resolved: Vec<FlagChange>,
+    
+    /// Flags on Zaps in both audits that appeared since the previous audit
+    pub flags_new: Vec<FlagChange>,
+    
+    /// Change in estimated monthly waste (current - previous) in USD
+    pub monthly_waste_usd_delta: f32,
+    
+    /// Per-Zap metric changes for Zaps present in both audits
+    pub zap_changes: Vec<ZapMetricsDelta>,
+}
+
+/// A flag that was resolved or newly appeared
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagChange {
+    /// Which Zap this belongs to
+    pub zap_id: String,
+    
+    /// Which flag changed
+    pub code: FlagCode,
+    
+    /// Stable flag id (empty when the source audit has none)
+    #[serde(default)]
+    pub flag_id: String,
+    
+    /// Estimated monthly savings in USD (from the audit where the flag exists)
+    pub estimated_monthly_savings_usd: f32,
+}
+
+/// Metric movement for a single Zap between two audits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapMetricsDelta {
+    /// Unique Zap identifier
+    pub zap_id: String,
+    
+    /// Zap name in the current audit
+    pub zap_name: String,
+    
+    /// Change in monthly task consumption (current - previous)
+    pub monthly_tasks_delta: i64,
+    
+    /// Change in error rate in percentage points, None if either audit lacks it
+    pub error_rate_delta: Option<f32>,
+}
+
+impl AuditDiffV1 {
+    /// Create new diff with the current schema version
+    pub fn new(
+        zaps_added: Vec<String>,
+        zaps_removed: Vec<String>,
+        flags_resolved: Vec<FlagChange>,
+        flags_new: Vec<FlagChange>,
+        monthly_waste_usd_delta: f32,
+        zap_changes: Vec<ZapMetricsDelta>,
+    ) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION.to_string(),
+            zaps_added,
+            zaps_removed,
+            flags_resolved,
+            flags_new,
+            monthly_waste_usd_delta,
+            zap_changes,
+        }
+    }
+}
+
 // ============================================================================
 // ENUMS
 // ============================================================================
@@ -330,7 +753,8 @@ pub enum Severity {
 }
 
 /// Efficiency flag type identifiers (v1.0.0)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Ordered by declaration (used to sort flags within a finding)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FlagCode {
     /// Multiple formatters in sequence
@@ -350,6 +774,21 @@ pub enum FlagCode {
     
     /// Paying for unused capacity
     PlanUnderutilization,
+    
+    /// Webhook trigger followed by a lookup of data the payload could carry
+    WebhookRedundantLookup,
+    
+    /// Consecutive filter steps that could be one filter
+    ChainedFilters,
+    
+    /// Trigger fires far more often than the account's other Zaps
+    HighVolumeTrigger,
+    
+    /// Tasks per run far off the norm for Zaps with the same step count
+    TaskStepRatioOutlier,
+    
+    /// Filter rejects nearly every run (trigger scoped too broadly)
+    OverFiltering,
 }
 
 /// Warning type identifiers (v1.0.0)
@@ -370,6 +809,42 @@ pub enum WarningCode {
 // HELPER IMPLEMENTATIONS
 // ============================================================================
 
+impl ConfidenceLevel {
+    /// Every variant, in declaration order
+    pub const ALL: [ConfidenceLevel; 3] = [ConfidenceLevel::High, ConfidenceLevel::Medium, ConfidenceLevel::Low];
+}
+
+impl Severity {
+    /// Every variant, in declaration order
+    pub const ALL: [Severity; 3] = [Severity::Low, Severity::Medium, Severity::High];
+}
+
+impl FlagCode {
+    /// Every variant, in declaration order
+    pub const ALL: [FlagCode; 11] = [
+        FlagCode::FormatterChain,
+        FlagCode::InterleavedTransformations,
+        FlagCode::TaskStepCostInflation,
+        FlagCode::LateFilter,
+        FlagCode::ZombieZap,
+        FlagCode::PlanUnderutilization,
+        FlagCode::WebhookRedundantLookup,
+        FlagCode::ChainedFilters,
+        FlagCode::HighVolumeTrigger,
+        FlagCode::TaskStepRatioOutlier,
+        FlagCode::OverFiltering,
+    ];
+}
+
+impl WarningCode {
+    /// Every variant, in declaration order
+    pub const ALL: [WarningCode; 3] = [
+        WarningCode::IncompleteData,
+        WarningCode::UnusualPattern,
+        WarningCode::HighComplexity,
+    ];
+}
+
 impl AuditMetadata {
     /// Create metadata with current timestamp
     pub fn new(
@@ -382,22 +857,58 @@ impl AuditMetadata {
             input_sources,
             pricing_assumptions,
             confidence_overview,
+            zaps_with_history: 0,
+            total_zaps: 0,
+            csv_rows_skipped: 0,
+            confidence_weights: ConfidenceWeights::default(),
         }
     }
 }
 
+impl FlagImpact {
+    /// Create impact with a point estimate (range collapses to the estimate)
+    /// NaN/infinite or negative amounts are clamped to 0 so a bad estimate degrades
+    /// gracefully instead of failing validate() after the whole audit is built
+    pub fn new(monthly_usd: f32, annual_usd: f32) -> Self {
+        let monthly = non_negative_usd(monthly_usd);
+        Self {
+            estimated_monthly_savings_usd: monthly,
+            estimated_annual_savings_usd: non_negative_usd(annual_usd),
+            estimated_monthly_savings_low_usd: monthly,
+            estimated_monthly_savings_high_usd: monthly,
+        }
+    }
+    
+    /// Set the monthly savings range (clamped the same way as the estimate)
+    pub fn with_band(mut self, low_usd: f32, high_usd: f32) -> Self {
+        self.estimated_monthly_savings_low_usd = non_negative_usd(low_usd);
+        self.estimated_monthly_savings_high_usd = non_negative_usd(high_usd);
+        self
+    }
+}
+
+/// Clamp a USD amount to a finite, non-negative value
+fn non_negative_usd(value: f32) -> f32 {
+    crate::guard_nan(value).max(0.0)
+}
+
 impl GlobalMetrics {
     /// Create empty metrics (all zeros)
     pub fn empty() -> Self {
         Self {
             total_zaps: 0,
             active_zaps: 0,
+            paused_zap_count: 0,
             total_monthly_tasks: 0,
             estimated_monthly_waste_tasks: 0,
             estimated_monthly_waste_usd: 0.0,
             estimated_annual_waste_usd: 0.0,
+            confidence_weighted_monthly_waste_usd: 0.0,
             zombie_zap_count: 0,
             high_severity_flag_count: 0,
+            fan_out_flows: 0,
+            formatter_usage_density: "low".to_string(),
+            waste_by_flag_code: BTreeMap::new(),
         }
     }
 }
@@ -414,6 +925,8 @@ impl ZapFinding {
                 steps: 0,
                 monthly_tasks: 0,
                 task_step_ratio: 0.0,
+                error_rate: None,
+                estimated_monthly_cost_usd: 0.0,
             },
             confidence: ConfidenceLevel::Low,
             flags: vec![],
@@ -421,8 +934,15 @@ impl ZapFinding {
                 Warning {
                     code: WarningCode::IncompleteData,
                     message: "Insufficient data for complete analysis".to_string(),
+                    meta: serde_json::Value::Null,
                 }
             ],
+            total_monthly_savings_usd: 0.0,
+            total_annual_savings_usd: 0.0,
+            steps: vec![],
+            step_costs: vec![],
+            usage: None,
+            folder: None,
         }
     }
 }
@@ -442,10 +962,261 @@ impl PlanAnalysis {
                 custom_logic: false,
             },
             downgrade_safe: false,
+            exceeds_plan_limit: false,
         }
     }
 }
 
+// ============================================================================
+// JSON SCHEMA
+// ============================================================================
+
+/// Serialized names of enum variants (as they appear in the JSON output)
+fn enum_values<T: Serialize>(variants: &[T]) -> Vec<serde_json::Value> {
+    variants.iter()
+        .map(|v| serde_json::to_value(v).unwrap_or(serde_json::Value::Null))
+        .collect()
+}
+
+/// Object schema; `required` lists the fields without #[serde(default)]
+fn object_schema(properties: serde_json::Value, required: &[&str]) -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// JSON Schema (draft 2020-12) describing AuditResultV1
+/// Hand-maintained: keep in sync with the structs above when adding fields
+pub fn audit_result_json_schema() -> serde_json::Value {
+    let string = json!({ "type": "string" });
+    let number = json!({ "type": "number" });
+    let integer = json!({ "type": "integer", "minimum": 0 });
+    let boolean = json!({ "type": "boolean" });
+    let string_array = json!({ "type": "array", "items": { "type": "string" } });
+    let def = |name: &str| json!({ "$ref": format!("#/$defs/{}", name) });
+    let array_of = |name: &str| json!({ "type": "array", "items": def(name) });
+    
+    let mut schema = object_schema(json!({
+        "schema_version": { "const": SCHEMA_VERSION },
+        "audit_metadata": def("AuditMetadata"),
+        "global_metrics": def("GlobalMetrics"),
+        "per_zap_findings": array_of("ZapFinding"),
+        "opportunities_ranked": array_of("RankedOpportunity"),
+        "plan_analysis": def("PlanAnalysis"),
+        "pattern_findings": array_of("PatternFinding"),
+        "parse_warnings": string_array,
+        "error_overview": array_of("ErrorMessageSummary"),
+        "by_folder": array_of("FolderSummary"),
+        "warnings": array_of("Warning"),
+    }), &["schema_version", "audit_metadata", "global_metrics", "per_zap_findings", "opportunities_ranked", "plan_analysis"]);
+    
+    schema["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+    schema["$id"] = json!(format!("https://zapier-lighthouse/audit-result-v{}.json", SCHEMA_VERSION));
+    schema["title"] = json!("AuditResultV1");
+    schema["schema_version"] = json!(SCHEMA_VERSION);
+    schema["$defs"] = json!({
+        "ConfidenceLevel": { "enum": enum_values(&ConfidenceLevel::ALL) },
+        "Severity": { "enum": enum_values(&Severity::ALL) },
+        "FlagCode": { "enum": enum_values(&FlagCode::ALL) },
+        "WarningCode": { "enum": enum_values(&WarningCode::ALL) },
+        
+        "AuditMetadata": object_schema(json!({
+            "generated_at": string,
+            "input_sources": def("InputSources"),
+            "pricing_assumptions": def("PricingAssumptions"),
+            "confidence_overview": def("ConfidenceOverview"),
+            "zaps_with_history": integer,
+            "total_zaps": integer,
+            "csv_rows_skipped": integer,
+            "confidence_weights": def("ConfidenceWeights"),
+        }), &["generated_at", "input_sources", "pricing_assumptions", "confidence_overview"]),
+        "InputSources": object_schema(json!({
+            "zap_json": boolean,
+            "task_csv": boolean,
+        }), &["zap_json", "task_csv"]),
+        "PricingAssumptions": object_schema(json!({
+            "plan_tier": string,
+            "task_price_usd": number,
+            "currency_code": { "enum": ["USD", "EUR", "GBP"] },
+            "cost_per_task_percentile": { "type": "number", "minimum": 0, "maximum": 1 },
+            "pricing_mode": { "enum": ["average", "marginal"] },
+            "pricing_note": string,
+            "fx_rate": { "type": "number", "exclusiveMinimum": 0 },
+        }), &["plan_tier", "task_price_usd"]),
+        "ConfidenceWeights": object_schema(json!({
+            "high": { "type": "number", "minimum": 0, "maximum": 1 },
+            "medium": { "type": "number", "minimum": 0, "maximum": 1 },
+            "low": { "type": "number", "minimum": 0, "maximum": 1 },
+        }), &["high", "medium", "low"]),
+        "ConfidenceOverview": object_schema(json!({
+            "high": integer,
+            "medium": integer,
+            "low": integer,
+        }), &["high", "medium", "low"]),
+        
+        "GlobalMetrics": object_schema(json!({
+            "total_zaps": integer,
+            "active_zaps": integer,
+            "paused_zap_count": integer,
+            "total_monthly_tasks": integer,
+            "estimated_monthly_waste_tasks": integer,
+            "estimated_monthly_waste_usd": number,
+            "estimated_annual_waste_usd": number,
+            "confidence_weighted_monthly_waste_usd": number,
+            "zombie_zap_count": integer,
+            "high_severity_flag_count": integer,
+            "fan_out_flows": integer,
+            "formatter_usage_density": string,
+            "waste_by_flag_code": {
+                "type": "object",
+                "propertyNames": def("FlagCode"),
+                "additionalProperties": number,
+            },
+        }), &[
+            "total_zaps", "active_zaps", "total_monthly_tasks", "estimated_monthly_waste_tasks",
+            "estimated_monthly_waste_usd", "estimated_annual_waste_usd", "zombie_zap_count",
+            "high_severity_flag_count",
+        ]),
+        
+        "ZapFinding": object_schema(json!({
+            "zap_id": string,
+            "zap_name": string,
+            "status": string,
+            "is_zombie": boolean,
+            "metrics": def("ZapMetrics"),
+            "confidence": def("ConfidenceLevel"),
+            "flags": array_of("EfficiencyFlag"),
+            "warnings": array_of("Warning"),
+            "total_monthly_savings_usd": number,
+            "total_annual_savings_usd": number,
+            "steps": array_of("StepInfo"),
+            "step_costs": array_of("StepCost"),
+            "usage": def("ZapUsage"),
+            "folder": def("ZapFolder"),
+        }), &["zap_id", "zap_name", "status", "is_zombie", "metrics", "confidence", "flags", "warnings"]),
+        "StepInfo": object_schema(json!({
+            "index": integer,
+            "parent_index": { "type": ["integer", "null"], "minimum": 0 },
+            "app_name": string,
+            "type_of": string,
+            "is_filter": boolean,
+            "is_formatter": boolean,
+            "paused": boolean,
+        }), &["index", "parent_index", "app_name", "type_of", "is_filter", "is_formatter", "paused"]),
+        "StepCost": object_schema(json!({
+            "index": integer,
+            "app_name": string,
+            "estimated_monthly_tasks": integer,
+            "estimated_monthly_usd": number,
+        }), &["index", "app_name", "estimated_monthly_tasks", "estimated_monthly_usd"]),
+        "ZapUsage": object_schema(json!({
+            "total_runs": integer,
+            "success_count": integer,
+            "error_count": integer,
+            "error_rate": number,
+            "most_common_error": { "type": ["string", "null"] },
+            "error_trend": { "type": ["string", "null"] },
+            "max_streak": integer,
+            "last_run": { "type": ["string", "null"] },
+            "tasks_used": { "type": ["integer", "null"], "minimum": 0 },
+            "run_distribution": def("RunDistribution"),
+        }), &["total_runs", "success_count", "error_count", "error_rate", "max_streak"]),
+        "RunDistribution": object_schema(json!({
+            "hourly_runs": { "type": "array", "items": integer, "minItems": 24, "maxItems": 24 },
+            "weekday_runs": { "type": "array", "items": integer, "minItems": 7, "maxItems": 7 },
+            "active_hours": integer,
+        }), &["hourly_runs", "weekday_runs", "active_hours"]),
+        "ZapMetrics": object_schema(json!({
+            "steps": integer,
+            "monthly_tasks": integer,
+            "task_step_ratio": number,
+            "error_rate": { "type": ["number", "null"] },
+            "estimated_monthly_cost_usd": number,
+        }), &["steps", "monthly_tasks", "task_step_ratio"]),
+        
+        "EfficiencyFlag": object_schema(json!({
+            "code": def("FlagCode"),
+            "flag_id": string,
+            "severity": def("Severity"),
+            "confidence": def("ConfidenceLevel"),
+            "impact": def("FlagImpact"),
+            "implementation": def("FlagImplementation"),
+            "meta": { "type": "object" },
+        }), &["code", "severity", "confidence", "impact", "implementation", "meta"]),
+        "FlagImpact": object_schema(json!({
+            "estimated_monthly_savings_usd": number,
+            "estimated_annual_savings_usd": number,
+            "estimated_monthly_savings_low_usd": number,
+            "estimated_monthly_savings_high_usd": number,
+        }), &["estimated_monthly_savings_usd", "estimated_annual_savings_usd"]),
+        "FlagImplementation": object_schema(json!({
+            "estimated_effort_hours": number,
+        }), &["estimated_effort_hours"]),
+        "Warning": object_schema(json!({
+            "code": def("WarningCode"),
+            "message": string,
+            "meta": { "type": "object" },
+        }), &["code", "message"]),
+        
+        "RankedOpportunity": object_schema(json!({
+            "zap_id": string,
+            "zap_name": string,
+            "flag_code": def("FlagCode"),
+            "estimated_monthly_savings_usd": number,
+            "confidence": def("ConfidenceLevel"),
+            "priority_score": number,
+            "rank": integer,
+        }), &["zap_id", "flag_code", "estimated_monthly_savings_usd", "confidence", "rank"]),
+        
+        "PlanAnalysis": object_schema(json!({
+            "current_plan": string,
+            "monthly_task_usage": integer,
+            "plan_task_capacity": def("PlanCapacity"),
+            "usage_percentile": number,
+            "premium_features_detected": def("PremiumFeatures"),
+            "downgrade_safe": boolean,
+            "exceeds_plan_limit": boolean,
+        }), &["current_plan", "monthly_task_usage", "plan_task_capacity", "usage_percentile", "premium_features_detected", "downgrade_safe"]),
+        "PlanCapacity": object_schema(json!({
+            "min": integer,
+            "max": integer,
+        }), &["min", "max"]),
+        "PremiumFeatures": object_schema(json!({
+            "paths": boolean,
+            "filters": boolean,
+            "webhooks": boolean,
+            "custom_logic": boolean,
+        }), &["paths", "filters", "webhooks", "custom_logic"]),
+        
+        "PatternFinding": object_schema(json!({
+            "pattern_type": string,
+            "zap_ids": string_array,
+            "message": string,
+            "guidance": string,
+            "meta": { "type": "object" },
+        }), &["pattern_type", "zap_ids", "message", "guidance", "meta"]),
+        
+        "ErrorMessageSummary": object_schema(json!({
+            "message": string,
+            "affected_zap_count": integer,
+            "total_occurrences": integer,
+        }), &["message", "affected_zap_count", "total_occurrences"]),
+        "ZapFolder": object_schema(json!({
+            "id": string,
+            "name": string,
+        }), &["id", "name"]),
+        "FolderSummary": object_schema(json!({
+            "folder": def("ZapFolder"),
+            "zap_count": integer,
+            "flag_count": integer,
+            "estimated_monthly_waste_usd": number,
+        }), &["folder", "zap_count", "flag_count", "estimated_monthly_waste_usd"]),
+    });
+    schema
+}
+
 // ============================================================================
 // VALIDATION
 // ============================================================================
@@ -460,12 +1231,29 @@ impl AuditResultV1 {
         if self.global_metrics.estimated_annual_waste_usd.is_nan() {
             return Err("Global metrics contains NaN in annual_waste_usd".to_string());
         }
+        if self.global_metrics.confidence_weighted_monthly_waste_usd.is_nan() {
+            return Err("Global metrics contains NaN in confidence_weighted_monthly_waste_usd".to_string());
+        }
+        for (code, waste) in &self.global_metrics.waste_by_flag_code {
+            if waste.is_nan() {
+                return Err(format!("Global metrics contains NaN in waste_by_flag_code[{:?}]", code));
+            }
+        }
         
         // Validate per-zap findings
         for finding in &self.per_zap_findings {
             if finding.metrics.task_step_ratio.is_nan() {
                 return Err(format!("Zap {} has NaN in task_step_ratio", finding.zap_id));
             }
+            if finding.metrics.estimated_monthly_cost_usd.is_nan() {
+                return Err(format!("Zap {} has NaN in estimated_monthly_cost_usd", finding.zap_id));
+            }
+            if finding.total_monthly_savings_usd.is_nan() || finding.total_annual_savings_usd.is_nan() {
+                return Err(format!("Zap {} has NaN in total savings", finding.zap_id));
+            }
+            if finding.total_monthly_savings_usd < 0.0 || finding.total_annual_savings_usd < 0.0 {
+                return Err(format!("Zap {} has negative total savings", finding.zap_id));
+            }
             
             // Validate flags
             for flag in &finding.flags {
@@ -475,6 +1263,10 @@ impl AuditResultV1 {
                 if flag.impact.estimated_monthly_savings_usd < 0.0 {
                     return Err(format!("Zap {} has negative savings", finding.zap_id));
                 }
+                if flag.impact.estimated_monthly_savings_low_usd.is_nan()
+                    || flag.impact.estimated_monthly_savings_high_usd.is_nan() {
+                    return Err(format!("Zap {} has flag with NaN savings range", finding.zap_id));
+                }
             }
         }
         
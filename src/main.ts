@@ -26,11 +26,12 @@ import { mapAuditToHandoffViewModel } from './handoffViewModelMapper'
 
 // Type definitions
 interface ZapSummary {
-  id: number
+  id: string
   title: string
   status: string
   step_count: number
   trigger_app: string
+  trigger_type: 'instant' | 'polling' | 'unknown'
   last_run: string | null
   error_rate: number | null
   total_runs: number
@@ -105,7 +106,7 @@ let cachedZipData: Uint8Array | null = null
 let zapList: ZapSummary[] = []
 
 // NEW: Batch Selection State Management
-let selectedZapIds: Set<number> = new Set()
+let selectedZapIds: Set<string> = new Set()
 
 // NEW: Cost Calibration State
 let pricePerTask: number = 0.02 // Default: $0.02/task (industry benchmark)
@@ -319,7 +320,7 @@ async function extractReAuditMetadata(pdfFile: File): Promise<ReAuditMetadata |
     isCustomPrice = true
       
       // Pre-select Zaps from metadata
-      selectedZapIds = new Set(metadata.zap_ids_analyzed.map(id => parseInt(id)))
+      selectedZapIds = new Set(metadata.zap_ids_analyzed)
       
     } catch (error) {
       console.error('Error processing PDF:', error)
@@ -543,7 +544,7 @@ function setReportType(type: 'audit' | 'continuity') {
 ;(window as any).setReportType = setReportType
 
 // NEW: Batch Selection Helper Functions
-function toggleZapSelection(zapId: number) {
+function toggleZapSelection(zapId: string) {
   if (selectedZapIds.has(zapId)) {
     selectedZapIds.delete(zapId)
   } else {
@@ -778,8 +779,8 @@ async function handleAnalyzeSelected() {
     const usage = includedTasks || 2000 // Use calibrated tasks from slider or default
 
     // 🔥 Call v1.0.0 analyze_zaps with selected IDs
-    const selectedIdsArray = Array.from(selectedZapIds).map(id => id.toString())
-    const resultJson = analyze_zaps(cachedZipData, selectedIdsArray, plan, usage)
+    const selectedIdsArray = Array.from(selectedZapIds)
+    const resultJson = analyze_zaps(cachedZipData, selectedIdsArray, plan, usage, 'monthly')
     const rawResult = JSON.parse(resultJson)
 
     // 🔥 VALIDATE before using (throws on invalid data)
@@ -1141,7 +1142,7 @@ function displayDeveloperEditionResults(auditResult: AuditResult) {
               tier_price: monthlyBill,
               price_per_task: pricePerTask
             },
-            zap_ids_analyzed: Array.from(selectedZapIds).map(id => id.toString()),
+            zap_ids_analyzed: Array.from(selectedZapIds),
             file_hash: fileHash,
             metadata_version: '1.0.0'
           }
@@ -1275,7 +1276,7 @@ function renderZapTable(filteredZaps: ZapSummary[]) {
               class="zap-checkbox w-4 h-4 text-blue-600 border-slate-300 rounded focus:ring-2 focus:ring-blue-500 cursor-pointer"
               data-zap-id="${zap.id}"
               ${isSelected ? 'checked' : ''}
-              onclick="event.stopPropagation(); toggleZapSelection(${zap.id})"
+              onclick="event.stopPropagation(); toggleZapSelection(this.dataset.zapId)"
             />
             <span class="text-slate-400 font-mono text-sm">#${index + 1}</span>
           </div>
@@ -1283,7 +1284,7 @@ function renderZapTable(filteredZaps: ZapSummary[]) {
           <!-- Title & Trigger -->
           <div class="col-span-4">
             <h3 class="font-bold text-slate-900 group-hover:text-blue-600 transition-colors mb-1">
-              ${zap.title === 'Untitled Zap' ? `Zap #${zap.id.toString().slice(-4)}` : zap.title}
+              ${zap.title === 'Untitled Zap' ? `Zap #${zap.id.slice(-4)}` : zap.title}
             </h3>
             <p class="text-xs text-slate-500">
               <span class="font-mono bg-slate-100 px-2 py-0.5 rounded">${zap.trigger_app}</span>
@@ -1358,7 +1359,7 @@ function renderZapTable(filteredZaps: ZapSummary[]) {
           // Select ALL (not just active)
           checkboxes.forEach(checkbox => {
             checkbox.checked = true
-            const zapId = parseInt(checkbox.dataset.zapId || '0')
+            const zapId = checkbox.dataset.zapId || ''
             selectedZapIds.add(zapId)
           })
         } else {
@@ -1377,7 +1378,7 @@ function renderZapTable(filteredZaps: ZapSummary[]) {
     const checkboxes = document.querySelectorAll<HTMLInputElement>('.zap-checkbox')
     checkboxes.forEach(checkbox => {
       checkbox.addEventListener('change', () => {
-        const zapId = parseInt(checkbox.dataset.zapId || '0')
+        const zapId = checkbox.dataset.zapId || ''
         if (checkbox.checked) {
           selectedZapIds.add(zapId)
         } else {
@@ -1666,7 +1667,8 @@ async function testV1API() {
       cachedZipData,
       [], // Empty array = analyze all Zaps
       currentPlanType, 
-      includedTasks || 2000
+      includedTasks || 2000,
+      'monthly'
     )
     const auditResult: AuditResult = JSON.parse(resultJson)
     
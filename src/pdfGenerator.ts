@@ -481,6 +481,11 @@ function getRootCauseLabel(flagType: string | undefined): string {
     'LATE_FILTER': 'Suboptimal Filter Placement',
     'ZOMBIE_ZAP': 'Dead Workflow Branch',
     'PLAN_UNDERUTILIZATION': 'Plan Cost Inefficiency',
+    'WEBHOOK_REDUNDANT_LOOKUP': 'Redundant Webhook Lookup',
+    'CHAINED_FILTERS': 'Chained Filter Steps',
+    'HIGH_VOLUME_TRIGGER': 'High-Volume Trigger',
+    'TASK_STEP_RATIO_OUTLIER': 'Unusual Task Consumption',
+    'OVER_FILTERING': 'Overly Broad Trigger',
   };
   return map[flagType] ?? 'Structural Inefficiency';
 }
@@ -504,6 +509,16 @@ function getRootCauseDescription(flagType: string | undefined): string {
       'Automation is active but not executing — deactivate to eliminate wasted plan capacity.',
     'PLAN_UNDERUTILIZATION': 
       'Current plan capacity significantly exceeds operational requirements.',
+    'WEBHOOK_REDUNDANT_LOOKUP': 
+      'Include the looked-up data in the webhook payload — removes a lookup task on every run.',
+    'CHAINED_FILTERS': 
+      'Merge consecutive filters into one — fewer steps to maintain with the same outcome.',
+    'HIGH_VOLUME_TRIGGER': 
+      'Narrow the trigger event or filter at the source — most runs likely do no useful work.',
+    'TASK_STEP_RATIO_OUTLIER': 
+      'Tasks per run are far from similar workflows — review loops and steps that rarely run.',
+    'OVER_FILTERING': 
+      'The filter rejects nearly every run — narrow the trigger so unwanted items never start the Zap.',
   };
   return map[flagType] ?? 'Structural inefficiency identified — review step configuration.';
 }
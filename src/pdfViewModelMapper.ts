@@ -30,6 +30,11 @@ const EFFORT_MAP: Record<string, number> = {
   INTERLEAVED_TRANSFORMATIONS: 15,
   TASK_STEP_COST_INFLATION: 20,
   PLAN_UNDERUTILIZATION: 5,
+  WEBHOOK_REDUNDANT_LOOKUP: 15,
+  CHAINED_FILTERS: 5,
+  HIGH_VOLUME_TRIGGER: 15,
+  TASK_STEP_RATIO_OUTLIER: 20,
+  OVER_FILTERING: 10,
 };
 
 /**
@@ -42,6 +47,11 @@ const ACTION_LABELS: Record<string, string> = {
   INTERLEAVED_TRANSFORMATIONS: 'Consolidate data transformations',
   TASK_STEP_COST_INFLATION: 'Optimize task usage',
   PLAN_UNDERUTILIZATION: 'Review plan tier',
+  WEBHOOK_REDUNDANT_LOOKUP: 'Send lookup data in the webhook payload',
+  CHAINED_FILTERS: 'Merge consecutive filters',
+  HIGH_VOLUME_TRIGGER: 'Narrow the trigger event',
+  TASK_STEP_RATIO_OUTLIER: 'Review steps that rarely run',
+  OVER_FILTERING: 'Narrow the trigger',
 };
 
 /**
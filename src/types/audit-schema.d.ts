@@ -41,6 +41,21 @@ export interface AuditResult {
   
   /** Analysis of Zapier plan utilization */
   plan_analysis: PlanAnalysis;
+  
+  /** Account-level patterns spanning several Zaps */
+  pattern_findings?: PatternFinding[];
+  
+  /** Zaps skipped because they failed to deserialize (tolerant parsing only) */
+  parse_warnings?: string[];
+  
+  /** Error messages tallied across all analyzed Zaps, most frequent first */
+  error_overview?: ErrorMessageSummary[];
+  
+  /** Waste and flag totals per Zapier folder, highest waste first */
+  by_folder?: FolderSummary[];
+  
+  /** Export-level warnings (e.g. no Zaps in the export) */
+  warnings?: Warning[];
 }
 
 // ============================================================================
@@ -63,6 +78,18 @@ export interface AuditMetadata {
   
   /** Distribution of confidence levels across findings */
   confidence_overview: ConfidenceOverview;
+  
+  /** Zaps with matching task history rows (out of total_zaps) */
+  zaps_with_history?: number;
+  
+  /** Zaps analyzed */
+  total_zaps?: number;
+  
+  /** Task history rows ignored (empty or invalid zap_id, repeated header rows, missing status, unreadable rows) */
+  csv_rows_skipped?: number;
+  
+  /** Confidence weights used for confidence-weighted waste and priority scores */
+  confidence_weights?: ConfidenceWeights;
 }
 
 /**
@@ -85,8 +112,42 @@ export interface PricingAssumptions {
   /** Zapier plan tier (e.g., "Professional", "Team") */
   plan_tier: string;
   
-  /** Cost per task in USD (derived from plan tier) */
+  /** Cost per task (derived from plan tier), in `currency_code` */
   task_price_usd: number;
+  
+  /** Currency of every `*_usd` figure; values are converted, names unchanged */
+  currency_code?: 'USD' | 'EUR' | 'GBP';
+  
+  /**
+   * Rank of the tier's per-task price within its plan (0 = cheapest, 1 = most expensive).
+   * Near 1 means per-task savings shrink on larger tiers. Absent for custom rates.
+   */
+  cost_per_task_percentile?: number;
+  
+  /**
+   * How saved tasks are valued: 'average' (tasks × task_price_usd) or
+   * 'marginal' (only the tier-price drop the reduced usage achieves)
+   */
+  pricing_mode?: 'average' | 'marginal';
+  
+  /**
+   * Caveat about how task_price_usd was derived (e.g. Enterprise priced from
+   * public Team tiers because no contracted rate was given)
+   */
+  pricing_note?: string;
+  
+  /** Units of `currency_code` per 1 USD used to convert prices (absent for USD) */
+  fx_rate?: number;
+}
+
+/**
+ * Share of estimated savings counted per confidence level (0-1).
+ * Defaults: high 1.0, medium 0.6, low 0.3.
+ */
+export interface ConfidenceWeights {
+  high: number;
+  medium: number;
+  low: number;
 }
 
 /**
@@ -119,6 +180,9 @@ export interface GlobalMetrics {
   /** Number of Zaps with status "on" */
   active_zaps: number;
   
+  /** Number of Zaps with status "paused" (not active, never zombies) */
+  paused_zap_count: number;
+  
   /** Total task consumption per month across all Zaps */
   total_monthly_tasks: number;
   
@@ -131,11 +195,23 @@ export interface GlobalMetrics {
   /** Estimated wasted cost per year in USD */
   estimated_annual_waste_usd: number;
   
+  /** Monthly waste weighted by flag confidence (High 1.0, Medium 0.6, Low 0.3) */
+  confidence_weighted_monthly_waste_usd: number;
+  
   /** Number of zombie Zaps (on but not running) */
   zombie_zap_count: number;
   
   /** Number of high-severity efficiency flags */
   high_severity_flag_count: number;
+  
+  /** Number of branching points (steps with more than one child, e.g. Paths) */
+  fan_out_flows: number;
+  
+  /** Share of Formatter/Code steps: "low" (<10%), "medium" (10–30%), "high" (>30%) */
+  formatter_usage_density: 'low' | 'medium' | 'high';
+  
+  /** Monthly waste in USD per flag code (pie-chart-ready breakdown) */
+  waste_by_flag_code: Partial<Record<FlagCode, number>>;
 }
 
 // ============================================================================
@@ -170,6 +246,127 @@ export interface PerZapFinding {
   
   /** Non-critical warnings (edge cases, incomplete data) */
   warnings: Warning[];
+  
+  /** Sum of this Zap's flag monthly savings in USD */
+  total_monthly_savings_usd?: number;
+  
+  /** Sum of this Zap's flag annual savings in USD */
+  total_annual_savings_usd?: number;
+  
+  /** Ordered step list (only when analyze_zaps is called with include_step_graph) */
+  steps?: StepInfo[];
+  
+  /** Per-step task and cost attribution (only with include_step_costs) */
+  step_costs?: StepCost[];
+  
+  /** Raw task history stats (absent in Partial mode) */
+  usage?: ZapUsage;
+  
+  /** Zapier folder the Zap is filed under (absent without folder data) */
+  folder?: ZapFolder;
+}
+
+/**
+ * Zapier folder reference.
+ */
+export interface ZapFolder {
+  /** Folder id as exported (numeric ids are stringified) */
+  id: string;
+  
+  /** Folder name (falls back to the id) */
+  name: string;
+}
+
+/**
+ * Estimated monthly cost of one step (trigger excluded).
+ */
+export interface StepCost {
+  /** Step position, matching StepInfo.index */
+  index: number;
+  
+  /** Human-readable app name */
+  app_name: string;
+  
+  /** Runs expected to reach this step per month (1 task each) */
+  estimated_monthly_tasks: number;
+  
+  /** estimated_monthly_tasks at the per-task price */
+  estimated_monthly_usd: number;
+}
+
+/**
+ * Execution statistics for a Zap, from task history CSVs.
+ */
+export interface ZapUsage {
+  /** Runs found in task history */
+  total_runs: number;
+  
+  /** Successful runs */
+  success_count: number;
+  
+  /** Failed runs */
+  error_count: number;
+  
+  /** Error rate percentage (0-100) */
+  error_rate: number;
+  
+  /** Most frequent error message */
+  most_common_error: string | null;
+  
+  /** Error trend */
+  error_trend: 'increasing' | 'stable' | 'decreasing' | null;
+  
+  /** Longest consecutive failure streak */
+  max_streak: number;
+  
+  /** Timestamp of the most recent run */
+  last_run: string | null;
+  
+  /** Tasks consumed, when the export has a task count column */
+  tasks_used: number | null;
+  
+  /** When the Zap runs, present when task history has parsable timestamps */
+  run_distribution?: RunDistribution;
+}
+
+/**
+ * Hour-of-day and day-of-week run counts for a Zap (UTC).
+ */
+export interface RunDistribution {
+  /** Runs per hour of day, 24 entries (index 0 = 00:00-00:59) */
+  hourly_runs: number[];
+  
+  /** Runs per weekday, 7 entries (index 0 = Monday) */
+  weekday_runs: number[];
+  
+  /** Hours of the day spanned by runs (24 minus the longest run-free stretch) */
+  active_hours: number;
+}
+
+/**
+ * One step of a Zap, in branch-aware traversal order.
+ */
+export interface StepInfo {
+  /** Position in the traversal (0 = trigger) */
+  index: number;
+  
+  /** Index of the parent step, null for the trigger */
+  parent_index: number | null;
+  
+  /** Human-readable app name */
+  app_name: string;
+  
+  /** Node type ("read" | "write" | ...) */
+  type_of: string;
+  
+  /** Whether this is a Filter step */
+  is_filter: boolean;
+  
+  /** Whether this is a Formatter/Code step */
+  is_formatter: boolean;
+  
+  /** Whether this step is paused */
+  paused: boolean;
 }
 
 /**
@@ -185,6 +382,12 @@ export interface ZapMetrics {
   
   /** Task/step ratio (higher = more efficient) */
   task_step_ratio: number;
+  
+  /** Error rate in percent (0-100), null without task history */
+  error_rate?: number | null;
+  
+  /** Total monthly spend on this Zap: monthly_tasks × task price (flags or not) */
+  estimated_monthly_cost_usd?: number;
 }
 
 // ============================================================================
@@ -199,6 +402,9 @@ export interface EfficiencyFlag {
   /** Unique identifier for this type of issue */
   code: FlagCode;
   
+  /** Stable id for this specific flag, same across re-runs (empty in older audits) */
+  flag_id?: string;
+  
   /** Technical severity of the issue */
   severity: Severity;
   
@@ -225,6 +431,12 @@ export interface FlagImpact {
   
   /** Estimated annual savings in USD if fixed */
   estimated_annual_savings_usd: number;
+  
+  /** Conservative end of the monthly savings range (equals the estimate when data-backed) */
+  estimated_monthly_savings_low_usd?: number;
+  
+  /** Optimistic end of the monthly savings range (equals the estimate when data-backed) */
+  estimated_monthly_savings_high_usd?: number;
 }
 
 /**
@@ -250,6 +462,9 @@ export interface Warning {
   
   /** Human-readable explanation */
   message: string;
+  
+  /** Warning-specific metadata (e.g. sub_zap_step_ids), omitted when empty */
+  meta?: Record<string, unknown>;
 }
 
 // ============================================================================
@@ -264,6 +479,9 @@ export interface RankedOpportunity {
   /** Which Zap this opportunity belongs to */
   zap_id: string;
   
+  /** Human-readable Zap name (no need to join with per_zap_findings) */
+  zap_name: string;
+  
   /** Which flag detected this opportunity */
   flag_code: FlagCode;
   
@@ -273,7 +491,10 @@ export interface RankedOpportunity {
   /** Confidence in this estimate */
   confidence: ConfidenceLevel;
   
-  /** Explicit ranking (1 = highest impact) */
+  /** Fix priority: savings × confidence weight / effort hours (quick, sure wins score high) */
+  priority_score?: number;
+  
+  /** Explicit ranking (1 = first to act on, by savings or priority_score) */
   rank: number;
 }
 
@@ -303,6 +524,9 @@ export interface PlanAnalysis {
   
   /** Whether downgrade is technically possible (may lose features) */
   downgrade_safe: boolean;
+  
+  /** Usage is above the plan's highest tier (Free: Zaps stop at the cap) */
+  exceeds_plan_limit?: boolean;
 }
 
 /**
@@ -362,7 +586,12 @@ export type FlagCode =
   | 'TASK_STEP_COST_INFLATION'     // Unnecessary steps inflating task count
   | 'LATE_FILTER'                  // Filter step after expensive operations
   | 'ZOMBIE_ZAP'                   // Zap is "on" but not executing
-  | 'PLAN_UNDERUTILIZATION';       // Paying for unused capacity
+  | 'PLAN_UNDERUTILIZATION'        // Paying for unused capacity
+  | 'WEBHOOK_REDUNDANT_LOOKUP'     // Webhook trigger followed by a redundant lookup step
+  | 'CHAINED_FILTERS'              // Consecutive filters that could be one filter
+  | 'HIGH_VOLUME_TRIGGER'          // Trigger fires far more often than the account's other Zaps
+  | 'TASK_STEP_RATIO_OUTLIER'      // Tasks per run far off the norm for the step count
+  | 'OVER_FILTERING';              // Filter rejects nearly every run
 
 /**
  * Warning type identifiers.
@@ -462,10 +691,119 @@ export function isFlagCode(value: unknown): value is FlagCode {
     'LATE_FILTER',
     'ZOMBIE_ZAP',
     'PLAN_UNDERUTILIZATION',
+    'WEBHOOK_REDUNDANT_LOOKUP',
+    'CHAINED_FILTERS',
+    'HIGH_VOLUME_TRIGGER',
+    'TASK_STEP_RATIO_OUTLIER',
+    'OVER_FILTERING',
   ];
   return typeof value === 'string' && validCodes.includes(value as FlagCode);
 }
 
+// ============================================================================
+// CROSS-ZAP PATTERNS
+// ============================================================================
+
+/**
+ * Account-level finding spanning several Zaps (batch mode only).
+ */
+export interface PatternFinding {
+  /** Pattern identifier (e.g. "duplicate_zaps") */
+  pattern_type: string;
+  
+  /** Zaps involved in this pattern */
+  zap_ids: string[];
+  
+  /** Human-readable explanation */
+  message: string;
+  
+  /** Recommended action */
+  guidance: string;
+  
+  /** Pattern-specific metadata */
+  meta: Record<string, unknown>;
+}
+
+/**
+ * One error message aggregated across the account's task history.
+ */
+export interface ErrorMessageSummary {
+  /** Error message as it appears in task history */
+  message: string;
+  
+  /** Number of Zaps that hit this error */
+  affected_zap_count: number;
+  
+  /** Total failed runs with this error across those Zaps */
+  total_occurrences: number;
+}
+
+/**
+ * Waste and flag totals for the Zaps in one folder.
+ */
+export interface FolderSummary {
+  /** Folder the totals belong to */
+  folder: ZapFolder;
+  
+  /** Zaps filed in this folder */
+  zap_count: number;
+  
+  /** Flags across those Zaps */
+  flag_count: number;
+  
+  /** Sum of those Zaps' monthly savings in USD */
+  estimated_monthly_waste_usd: number;
+}
+
+// ============================================================================
+// AUDIT DIFF
+// ============================================================================
+
+/**
+ * Month-over-month changes between two audits.
+ * Returned by diff_audits(prev_json, curr_json).
+ */
+export interface AuditDiffV1 {
+  /** Schema version */
+  schema_version: string;
+  
+  /** Zap IDs present only in the current audit */
+  zaps_added: string[];
+  
+  /** Zap IDs present only in the previous audit */
+  zaps_removed: string[];
+  
+  /** Flags on Zaps in both audits that no longer appear */
+  flags_resolved: FlagChange[];
+  
+  /** Flags on Zaps in both audits that appeared since the previous audit */
+  flags_new: FlagChange[];
+  
+  /** Change in estimated monthly waste (current - previous) in USD */
+  monthly_waste_usd_delta: number;
+  
+  /** Per-Zap metric changes for Zaps present in both audits */
+  zap_changes: ZapMetricsDelta[];
+}
+
+export interface FlagChange {
+  zap_id: string;
+  code: FlagCode;
+  flag_id?: string;
+  estimated_monthly_savings_usd: number;
+}
+
+export interface ZapMetricsDelta {
+  zap_id: string;
+  zap_name: string;
+  
+  /** Change in monthly task consumption (current - previous) */
+  monthly_tasks_delta: number;
+  
+  /** Change in error rate in percentage points, null if either audit lacks it */
+  error_rate_delta: number | null;
+}
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
@@ -487,6 +825,11 @@ export const FLAG_CODES: readonly FlagCode[] = [
   'LATE_FILTER',
   'ZOMBIE_ZAP',
   'PLAN_UNDERUTILIZATION',
+  'WEBHOOK_REDUNDANT_LOOKUP',
+  'CHAINED_FILTERS',
+  'HIGH_VOLUME_TRIGGER',
+  'TASK_STEP_RATIO_OUTLIER',
+  'OVER_FILTERING',
 ] as const;
 
 /**